@@ -0,0 +1,16 @@
+pub mod test;
+
+use argh::FromArgs;
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+pub enum Subcommand {
+    Test(test::TestCommand),
+}
+
+/// Runs whichever subcommand the user invoked.
+pub fn dispatch(command: Subcommand) -> Result<(), String> {
+    match command {
+        Subcommand::Test(cmd) => test::run(cmd),
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use argh::FromArgs;
+use kube::core::DynamicObject;
+
+use crate::constraint::ConstraintInfo;
+use crate::context::ContextReader;
+use crate::crd::Constraint;
+use crate::evaluator::{evaluate_constraint_audit, Violation};
+
+#[derive(FromArgs, PartialEq, Debug)]
+/// dry-run one or more constraints against local manifests, entirely offline
+#[argh(subcommand, name = "test")]
+pub struct TestCommand {
+    /// path to a Constraint CRD YAML/JSON file (repeatable)
+    #[argh(option, short = 'c')]
+    constraint: Vec<PathBuf>,
+
+    /// path to a target object YAML/JSON file to evaluate (repeatable)
+    #[argh(option, short = 'o')]
+    object: Vec<PathBuf>,
+}
+
+/// Runs `cmd` and returns `Err` with a summary message if any
+/// (constraint, object) pair was rejected.
+pub fn run(cmd: TestCommand) -> Result<(), String> {
+    let constraints: Vec<ConstraintInfo> = cmd
+        .constraint
+        .iter()
+        .map(|path| load_constraint(path))
+        .collect::<Result<_, _>>()?;
+    let objects: Vec<(PathBuf, DynamicObject)> = cmd
+        .object
+        .iter()
+        .map(|path| load_object(path).map(|object| (path.clone(), object)))
+        .collect::<Result<_, _>>()?;
+
+    let cache = Mutex::new(HashMap::new());
+    // No cluster connection in offline mode: `context.get(...)` calls in a
+    // rule will fail with a clear error rather than silently returning None.
+    let context_reader = ContextReader::disabled();
+    let (event_sender, _event_receiver) = tokio::sync::mpsc::unbounded_channel();
+    let mut any_rejected = false;
+    for constraint in &constraints {
+        for (object_path, object) in &objects {
+            let (allowed, violations, patch) = evaluate_constraint_audit(
+                &cache,
+                &context_reader,
+                &event_sender,
+                constraint,
+                object.clone(),
+            );
+            print_outcome(&constraint.name, object_path, allowed, &violations, &patch);
+            any_rejected |= !allowed;
+        }
+    }
+
+    if any_rejected {
+        Err("one or more (constraint, object) pairs were rejected".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn load_constraint(path: &PathBuf) -> Result<ConstraintInfo, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    let constraint: Constraint = serde_yaml::from_str(&contents)
+        .map_err(|err| format!("could not parse {}: {}", path.display(), err))?;
+    let name = constraint
+        .metadata
+        .name
+        .clone()
+        .ok_or_else(|| format!("{} has no metadata.name", path.display()))?;
+    Ok(ConstraintInfo::new(name, constraint.spec, Default::default()))
+}
+
+fn load_object(path: &PathBuf) -> Result<DynamicObject, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    serde_yaml::from_str(&contents)
+        .map_err(|err| format!("could not parse {}: {}", path.display(), err))
+}
+
+fn print_outcome(
+    constraint_name: &str,
+    object_path: &PathBuf,
+    allowed: bool,
+    violations: &[Violation],
+    patch: &Option<json_patch::Patch>,
+) {
+    let verdict = if allowed { "ALLOWED" } else { "REJECTED" };
+    println!(
+        "{} vs {}: {}",
+        constraint_name,
+        object_path.display(),
+        verdict
+    );
+    for violation in violations {
+        println!("  violation: {}", violation);
+    }
+    if let Some(patch) = patch {
+        match serde_json::to_string_pretty(&patch.0) {
+            Ok(rendered) => println!("  patch: {}", rendered),
+            Err(err) => println!("  patch: <could not render: {}>", err),
+        }
+    }
+}
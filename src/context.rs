@@ -0,0 +1,156 @@
+//! A narrow, explicitly-granted capability that Python rules can use to look
+//! up other cluster objects (e.g. "reject this Pod unless its Namespace
+//! carries label X"), instead of reasoning purely over the admitted object.
+//!
+//! A rule only ever sees what its constraint's `context` allowlist grants,
+//! and every lookup goes through [`PyContext::get`] so it can be metered and
+//! audited the same way constraint evaluations are.
+
+use kube::api::{Api, DynamicObject};
+use kube::core::{ApiResource, GroupVersionKind};
+use lazy_static::lazy_static;
+use prometheus::{register_counter_vec, CounterVec};
+use pyo3::exceptions::{PyPermissionError, PyRuntimeError};
+use pyo3::prelude::*;
+
+use crate::events::{ConstraintEvent, ConstraintEventData, ConstraintReference, EventSender};
+
+lazy_static! {
+    static ref CONTEXT_LOOKUPS: CounterVec = register_counter_vec!(
+        "bridgekeeper_context_lookup",
+        "Number of cross-resource context lookups made by constraint rules.",
+        &["name", "kind", "allowed"]
+    )
+    .unwrap();
+}
+
+/// Reads other cluster objects on behalf of a constraint's Python rule.
+/// Holds a shared client so repeated lookups don't each pay for a fresh
+/// connection.
+#[derive(Clone)]
+pub struct ContextReader {
+    backend: Option<(kube::Client, tokio::runtime::Handle)>,
+}
+
+impl ContextReader {
+    pub fn new(client: kube::Client, runtime: tokio::runtime::Handle) -> Self {
+        ContextReader {
+            backend: Some((client, runtime)),
+        }
+    }
+
+    /// A reader with no backing cluster connection. Used for offline
+    /// evaluation (e.g. `bridgekeeper test`); any lookup fails with a clear
+    /// error instead of panicking for lack of a client.
+    pub fn disabled() -> Self {
+        ContextReader { backend: None }
+    }
+
+    fn get(
+        &self,
+        gvk: &GroupVersionKind,
+        namespace: Option<&str>,
+        name: &str,
+    ) -> Result<Option<DynamicObject>, String> {
+        let (client, runtime) = self
+            .backend
+            .as_ref()
+            .ok_or_else(|| "no cluster connection available for context lookups".to_string())?;
+        let api_resource = ApiResource::from_gvk(gvk);
+        let api: Api<DynamicObject> = match namespace {
+            Some(ns) => Api::namespaced_with(client.clone(), ns, &api_resource),
+            None => Api::all_with(client.clone(), &api_resource),
+        };
+        runtime
+            .block_on(api.get_opt(name))
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// The capability object passed as the second argument to a constraint's
+/// `validate` function. A rule can only reach the group/version/kinds its
+/// constraint explicitly allowlists in `spec.context`.
+#[pyclass]
+pub struct PyContext {
+    constraint_name: String,
+    ref_info: ConstraintReference,
+    allowed: Vec<GroupVersionKind>,
+    reader: ContextReader,
+    event_sender: EventSender,
+}
+
+impl PyContext {
+    pub fn new(
+        constraint_name: String,
+        ref_info: ConstraintReference,
+        allowed: Vec<GroupVersionKind>,
+        reader: ContextReader,
+        event_sender: EventSender,
+    ) -> Self {
+        PyContext {
+            constraint_name,
+            ref_info,
+            allowed,
+            reader,
+            event_sender,
+        }
+    }
+
+    fn is_allowed(&self, gvk: &GroupVersionKind) -> bool {
+        self.allowed.iter().any(|candidate| candidate == gvk)
+    }
+}
+
+#[pymethods]
+impl PyContext {
+    /// Looks up a single object by group/version/kind/namespace/name,
+    /// returning it pythonized, or `None` if it doesn't exist.
+    #[pyo3(signature = (group, version, kind, name, namespace=None))]
+    fn get(
+        &self,
+        py: Python,
+        group: String,
+        version: String,
+        kind: String,
+        name: String,
+        namespace: Option<String>,
+    ) -> PyResult<PyObject> {
+        let gvk = GroupVersionKind::gvk(&group, &version, &kind);
+        let allowed = self.is_allowed(&gvk);
+        CONTEXT_LOOKUPS
+            .with_label_values(&[
+                self.constraint_name.as_str(),
+                kind.as_str(),
+                if allowed { "true" } else { "false" },
+            ])
+            .inc();
+        self.event_sender
+            .send(ConstraintEvent {
+                constraint_reference: self.ref_info.clone(),
+                event_data: ConstraintEventData::ContextLookup {
+                    kind: kind.clone(),
+                    namespace: namespace.clone(),
+                    name: name.clone(),
+                    allowed,
+                },
+            })
+            .unwrap_or_else(|err| log::warn!("Could not send event: {:?}", err));
+
+        if !allowed {
+            return Err(PyPermissionError::new_err(format!(
+                "constraint '{}' is not allowed to read {}/{} (add it to the constraint's context allowlist)",
+                self.constraint_name, group, kind
+            )));
+        }
+
+        // Release the GIL for the blocking round-trip to the API server so a
+        // lookup doesn't serialize every other rule evaluation in the
+        // process (this `validate` call runs on a blocking thread; see
+        // `ConstraintEvaluator::evaluate_constraints`'s doc comment).
+        let result = py.allow_threads(|| self.reader.get(&gvk, namespace.as_deref(), &name));
+        match result.map_err(PyRuntimeError::new_err)? {
+            Some(object) => pythonize::pythonize(py, &object).map_err(Into::into),
+            None => Ok(py.None()),
+        }
+    }
+}
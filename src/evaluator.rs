@@ -1,5 +1,6 @@
 use crate::{
     constraint::{ConstraintInfo, ConstraintStoreRef},
+    context::{ContextReader, PyContext},
     crd::Constraint,
     events::{ConstraintEvent, ConstraintEventData, EventSender},
 };
@@ -10,7 +11,11 @@ use kube::core::{
 use lazy_static::lazy_static;
 use prometheus::{register_counter_vec, CounterVec};
 use pyo3::prelude::*;
-use serde_derive::Serialize;
+use pyo3::types::PyList;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
 
 lazy_static! {
@@ -44,6 +49,78 @@ lazy_static! {
         &["name"]
     )
     .unwrap();
+    static ref CONSTRAINT_EVALUATIONS_BY_MODE: CounterVec = register_counter_vec!(
+        "bridgekeeper_constraint_evaluated_by_mode",
+        "Number of constraint evaluations broken down by enforcement mode.",
+        &["name", "mode"]
+    )
+    .unwrap();
+}
+
+/// Controls what a failed constraint evaluation actually does to the
+/// admission request. Lets operators roll out a new constraint as
+/// observe-only before switching it to enforcing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnforcementAction {
+    /// Reject the request; no other constraints are evaluated.
+    Deny,
+    /// Allow the request but surface the failure reason as a warning.
+    Warn,
+    /// Allow the request, record metrics and an event, but never warn or block.
+    Dryrun,
+    /// Like `Warn`, but additionally drops any mutation patch the constraint produced.
+    Audit,
+}
+
+impl Default for EnforcementAction {
+    fn default() -> Self {
+        EnforcementAction::Deny
+    }
+}
+
+/// Accepts either the current lowercase string variants or the legacy
+/// boolean `enforce` value, so a deployed constraint that set `enforce:
+/// false` deserializes to `Warn` instead of silently upgrading to a hard
+/// `Deny` the first time it's read with this type.
+impl<'de> Deserialize<'de> for EnforcementAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            LegacyEnforce(bool),
+            Named(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::LegacyEnforce(true) => Ok(EnforcementAction::Deny),
+            Repr::LegacyEnforce(false) => Ok(EnforcementAction::Warn),
+            Repr::Named(name) => match name.as_str() {
+                "deny" => Ok(EnforcementAction::Deny),
+                "warn" => Ok(EnforcementAction::Warn),
+                "dryrun" => Ok(EnforcementAction::Dryrun),
+                "audit" => Ok(EnforcementAction::Audit),
+                other => Err(serde::de::Error::unknown_variant(
+                    other,
+                    &["deny", "warn", "dryrun", "audit"],
+                )),
+            },
+        }
+    }
+}
+
+impl EnforcementAction {
+    fn label(&self) -> &'static str {
+        match self {
+            EnforcementAction::Deny => "deny",
+            EnforcementAction::Warn => "warn",
+            EnforcementAction::Dryrun => "dryrun",
+            EnforcementAction::Audit => "audit",
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -70,12 +147,14 @@ impl ValidationRequest {
 pub struct ConstraintEvaluator {
     constraints: ConstraintStoreRef,
     event_sender: EventSender,
+    module_cache: Mutex<HashMap<String, (u64, Py<PyModule>)>>,
+    context_reader: ContextReader,
 }
 
 pub struct EvaluationResult {
     pub allowed: bool,
     pub reason: Option<String>,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Violation>,
     pub patch: Option<json_patch::Patch>,
 }
 
@@ -85,15 +164,25 @@ impl ConstraintEvaluator {
     pub fn new(
         constraints: ConstraintStoreRef,
         event_sender: EventSender,
+        context_reader: ContextReader,
     ) -> ConstraintEvaluatorRef {
         let evaluator = ConstraintEvaluator {
             constraints,
             event_sender,
+            module_cache: Mutex::new(HashMap::new()),
+            context_reader,
         };
         pyo3::prepare_freethreaded_python();
         Arc::new(Mutex::new(evaluator))
     }
 
+    /// Evaluates `admission_request` against every matching constraint.
+    ///
+    /// A rule's `context.get(...)` calls release the GIL but still block the
+    /// calling OS thread on a cluster round-trip (see [`ContextReader::get`]),
+    /// so callers must invoke this from a blocking context (e.g. behind
+    /// `tokio::task::spawn_blocking` in an async webhook handler) rather than
+    /// directly on an async executor thread.
     pub fn evaluate_constraints(
         &self,
         admission_request: admission::AdmissionRequest<DynamicObject>,
@@ -136,12 +225,18 @@ impl ConstraintEvaluator {
                         namespace.clone().unwrap_or_else(|| "-".to_string()),
                         name
                     );
-                    let res = evaluate_constraint(value, &request);
-                    if let Some(mut patch) = res.2 {
-                        if let Some(patches) = patches.as_mut() {
-                            patches.0.append(&mut patch.0);
-                        } else {
-                            patches = Some(patch);
+                    let res = self.evaluate_constraint(value, &request);
+                    let mode = value.constraint.enforcement.unwrap_or_default();
+                    CONSTRAINT_EVALUATIONS_BY_MODE
+                        .with_label_values(&[value.name.as_str(), mode.label()])
+                        .inc();
+                    if matches!(mode, EnforcementAction::Deny | EnforcementAction::Warn) {
+                        if let Some(mut patch) = res.2 {
+                            if let Some(patches) = patches.as_mut() {
+                                patches.0.append(&mut patch.0);
+                            } else {
+                                patches = Some(patch);
+                            }
                         }
                     }
                     self.event_sender
@@ -150,7 +245,7 @@ impl ConstraintEvaluator {
                             event_data: ConstraintEventData::Evaluated {
                                 target_identifier,
                                 result: res.0,
-                                reason: res.1.clone(),
+                                reason: violations_reason(&res.1),
                             },
                         })
                         .unwrap_or_else(|err| log::warn!("Could not send event: {:?}", err));
@@ -159,8 +254,13 @@ impl ConstraintEvaluator {
                             .with_label_values(&[value.name.as_str()])
                             .inc();
                         log::info!("Constraint '{}' evaluates to {}", value.name, res.0);
-                        if res.1.is_some() {
-                            warnings.push(res.1.unwrap());
+                        match mode {
+                            EnforcementAction::Dryrun => {
+                                // Observed via the metrics/event above; never warns or blocks.
+                            }
+                            _ => {
+                                warnings.extend(res.1);
+                            }
                         }
                     } else {
                         CONSTRAINT_EVALUATIONS_REJECT
@@ -170,18 +270,24 @@ impl ConstraintEvaluator {
                             "Constraint '{}' evaluates to {} with message '{}'",
                             value.name,
                             res.0,
-                            res.1.as_ref().unwrap()
+                            render_violations(&res.1)
                         );
-                        if value.constraint.enforce.unwrap_or(true) {
-                            // If one constraint fails no need to evaluate the others
-                            return EvaluationResult {
-                                allowed: res.0,
-                                reason: res.1,
-                                warnings,
-                                patch: None,
-                            };
-                        } else {
-                            warnings.push(res.1.unwrap());
+                        match mode {
+                            EnforcementAction::Deny => {
+                                // If one constraint fails no need to evaluate the others
+                                return EvaluationResult {
+                                    allowed: res.0,
+                                    reason: violations_reason(&res.1),
+                                    warnings,
+                                    patch: None,
+                                };
+                            }
+                            EnforcementAction::Warn | EnforcementAction::Audit => {
+                                warnings.extend(res.1);
+                            }
+                            EnforcementAction::Dryrun => {
+                                // Observed via the metrics/event above; never warns or blocks.
+                            }
                         }
                     }
                 }
@@ -202,11 +308,17 @@ impl ConstraintEvaluator {
         request: &admission::AdmissionRequest<Constraint>,
     ) -> (bool, Option<String>) {
         if let Some(constraint) = request.object.as_ref() {
-            let python_code = constraint.spec.rule.python.clone();
+            let name = constraint.metadata.name.as_ref().unwrap().clone();
+            let info = ConstraintInfo::new(name.clone(), constraint.spec.clone(), Default::default());
             Python::with_gil(|py| {
-                if let Err(err) = PyModule::from_code(py, &python_code, "rule.py", "bridgekeeper") {
+                // Routed through the same module cache as per-admission
+                // evaluation: this runs once per constraint create/update
+                // rather than once per admission, but there's no reason to
+                // pay for a second, separate compile the first time it's
+                // actually evaluated.
+                if let Err(err) = compiled_module(py, &self.module_cache, &info) {
                     CONSTRAINT_VALIDATIONS_FAIL
-                        .with_label_values(&[constraint.metadata.name.as_ref().unwrap().as_str()])
+                        .with_label_values(&[name.as_str()])
                         .inc();
                     (false, Some(format!("Python compile error: {:?}", err)))
                 } else {
@@ -217,55 +329,224 @@ impl ConstraintEvaluator {
             (false, Some("No rule found".to_string()))
         }
     }
+
+    fn evaluate_constraint(
+        &self,
+        constraint: &ConstraintInfo,
+        request: &ValidationRequest,
+    ) -> (bool, Vec<Violation>, Option<json_patch::Patch>) {
+        evaluate_constraint(
+            &self.module_cache,
+            &self.context_reader,
+            &self.event_sender,
+            constraint,
+            request,
+        )
+    }
+
+    pub fn evaluate_constraint_audit(
+        &self,
+        constraint: &ConstraintInfo,
+        object: DynamicObject,
+    ) -> (bool, Vec<Violation>, Option<json_patch::Patch>) {
+        evaluate_constraint_audit(
+            &self.module_cache,
+            &self.context_reader,
+            &self.event_sender,
+            constraint,
+            object,
+        )
+    }
+}
+
+type ModuleCache = Mutex<HashMap<String, (u64, Py<PyModule>)>>;
+
+/// Returns the compiled `validate` module for `constraint`, reusing `cache`'s
+/// stored module when the rule source hasn't changed since it was last
+/// compiled, and compiling (and caching) it otherwise.
+fn compiled_module<'p>(
+    py: Python<'p>,
+    cache: &ModuleCache,
+    constraint: &ConstraintInfo,
+) -> PyResult<&'p PyModule> {
+    let python_code = &constraint.constraint.rule.python;
+    let mut hasher = DefaultHasher::new();
+    python_code.hash(&mut hasher);
+    let source_hash = hasher.finish();
+
+    if let Ok(cache) = cache.lock() {
+        if let Some((cached_hash, module)) = cache.get(&constraint.name) {
+            if *cached_hash == source_hash {
+                return Ok(module.clone_ref(py).into_ref(py));
+            }
+        }
+    }
+
+    let module = PyModule::from_code(py, python_code, "rule.py", "bridgekeeper")?;
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(constraint.name.clone(), (source_hash, Py::from(module)));
+    }
+    Ok(module)
 }
 
 fn evaluate_constraint(
+    cache: &ModuleCache,
+    context_reader: &ContextReader,
+    event_sender: &EventSender,
     constraint: &ConstraintInfo,
     request: &ValidationRequest,
-) -> (bool, Option<String>, Option<json_patch::Patch>) {
+) -> (bool, Vec<Violation>, Option<json_patch::Patch>) {
     let name = &constraint.name;
     Python::with_gil(|py| {
         let obj = pythonize::pythonize(py, &request).unwrap();
-        if let Ok(rule_code) = PyModule::from_code(
-            py,
-            &constraint.constraint.rule.python,
-            "rule.py",
-            "bridgekeeper",
-        ) {
-            if let Ok(validation_function) = rule_code.getattr("validate") {
-                match validation_function.call1((obj,)) {
-                    Ok(result) => extract_result(name, request, result),
-                    Err(err) => fail(name, &format!("Validation function failed: {}", err)),
+        match compiled_module(py, cache, constraint) {
+            Ok(rule_code) => {
+                if let Ok(validation_function) = rule_code.getattr("validate") {
+                    let context = PyContext::new(
+                        constraint.name.clone(),
+                        constraint.ref_info.clone(),
+                        constraint.constraint.context.clone(),
+                        context_reader.clone(),
+                        event_sender.clone(),
+                    );
+                    let context = match Py::new(py, context) {
+                        Ok(context) => context,
+                        Err(err) => {
+                            return fail(name, &format!("Could not set up context: {}", err))
+                        }
+                    };
+                    match validation_function.call1((obj, context)) {
+                        Ok(result) => extract_result(name, request, result),
+                        Err(err) => fail(name, &format!("Validation function failed: {}", err)),
+                    }
+                } else {
+                    fail(name, "Validation function not found in code")
                 }
-            } else {
-                fail(name, "Validation function not found in code")
             }
-        } else {
-            fail(name, "Validation function could not be compiled")
+            Err(_) => fail(name, "Validation function could not be compiled"),
         }
     })
 }
 
+/// Evaluates `constraint` against `object` as an `Update`, for use outside the
+/// admission webhook path (audits, offline dry-runs). `cache` is the compiled
+/// module cache to reuse/populate; callers without a long-lived
+/// [`ConstraintEvaluator`] may pass a fresh one, as may `context_reader` (see
+/// [`ContextReader::disabled`]).
 pub fn evaluate_constraint_audit(
+    cache: &ModuleCache,
+    context_reader: &ContextReader,
+    event_sender: &EventSender,
     constraint: &ConstraintInfo,
     object: DynamicObject,
-) -> (bool, Option<String>, Option<json_patch::Patch>) {
+) -> (bool, Vec<Violation>, Option<json_patch::Patch>) {
     let request = ValidationRequest {
         object,
         operation: Operation::Update,
     };
-    evaluate_constraint(constraint, &request)
+    evaluate_constraint(cache, context_reader, event_sender, constraint, &request)
+}
+
+/// A single problem a rule found with the admitted object. `severity`,
+/// `field_path` and `code` are optional so a rule can return as much or as
+/// little structure as it has; a plain string reason becomes a `Violation`
+/// with only `message` set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Violation {
+    pub message: String,
+    #[serde(default)]
+    pub severity: Option<String>,
+    #[serde(default)]
+    pub field_path: Option<String>,
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
+impl Violation {
+    fn new(message: String) -> Self {
+        Violation {
+            message,
+            severity: None,
+            field_path: None,
+            code: None,
+        }
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(field_path) = &self.field_path {
+            write!(f, "{}: ", field_path)?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(code) = &self.code {
+            write!(f, " [{}]", code)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders violations as a single readable summary for the admission
+/// response or a log line.
+fn render_violations(violations: &[Violation]) -> String {
+    violations
+        .iter()
+        .map(Violation::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn violations_reason(violations: &[Violation]) -> Option<String> {
+    if violations.is_empty() {
+        None
+    } else {
+        Some(render_violations(violations))
+    }
+}
+
+/// Parses a Python list returned by `validate` into `Violation`s. Each item
+/// may be a plain string (becomes `Violation.message`) or a dict with
+/// `message` plus any of `severity`, `field_path`, `code`. Any other item
+/// becomes a `Violation` whose message is the item's `str()`.
+fn parse_violations(items: &PyList) -> Vec<Violation> {
+    items
+        .iter()
+        .map(|item| {
+            item.extract::<String>().map(Violation::new).unwrap_or_else(|_| {
+                pythonize::depythonize::<Violation>(item)
+                    .unwrap_or_else(|_| Violation::new(item.to_string()))
+            })
+        })
+        .collect()
 }
 
 fn extract_result(
     name: &String,
     request: &ValidationRequest,
     result: &PyAny,
-) -> (bool, Option<String>, Option<json_patch::Patch>) {
-    if let Ok((code, reason, patched)) = result.extract::<(bool, Option<String>, &PyAny)>() {
+) -> (bool, Vec<Violation>, Option<json_patch::Patch>) {
+    if let Ok((code, violations, patched)) =
+        result.extract::<(bool, &PyList, &PyAny)>()
+    {
+        let violations = parse_violations(violations);
+        if let Ok(result) = pythonize::depythonize::<serde_json::Value>(patched) {
+            match generate_patches(&request.object, &result) {
+                Ok(patch) => (code, violations, Some(patch)),
+                Err(error) => fail(name, &format!("failed to compute patch: {}", error)),
+            }
+        } else {
+            fail(
+                name,
+                "Could not read patched object returned by validation function",
+            )
+        }
+    } else if let Ok((code, violations)) = result.extract::<(bool, &PyList)>() {
+        (code, parse_violations(violations), None)
+    } else if let Ok((code, reason, patched)) = result.extract::<(bool, Option<String>, &PyAny)>()
+    {
         if let Ok(result) = pythonize::depythonize::<serde_json::Value>(patched) {
             match generate_patches(&request.object, &result) {
-                Ok(patch) => (code, reason, Some(patch)),
+                Ok(patch) => (code, reason.map(Violation::new).into_iter().collect(), Some(patch)),
                 Err(error) => fail(name, &format!("failed to compute patch: {}", error)),
             }
         } else {
@@ -275,19 +556,23 @@ fn extract_result(
             )
         }
     } else if let Ok((code, reason)) = result.extract::<(bool, Option<String>)>() {
-        (code, reason, None)
+        (code, reason.map(Violation::new).into_iter().collect(), None)
+    } else if let Ok(violations) = result.extract::<&PyList>() {
+        let violations = parse_violations(violations);
+        let code = violations.is_empty();
+        (code, violations, None)
     } else if let Ok(code) = result.extract::<bool>() {
-        (code, None, None)
+        (code, Vec::new(), None)
     } else {
         fail(name, "Validation function did not return expected types")
     }
 }
 
-fn fail(name: &str, reason: &str) -> (bool, Option<String>, Option<json_patch::Patch>) {
+fn fail(name: &str, reason: &str) -> (bool, Vec<Violation>, Option<json_patch::Patch>) {
     CONSTRAINT_EVALUATIONS_ERROR
         .with_label_values(&[name])
         .inc();
-    (false, Some(reason.to_string()), None)
+    (false, vec![Violation::new(reason.to_string())], None)
 }
 
 fn generate_patches(
@@ -311,7 +596,7 @@ mod tests {
     fn test_simple_evaluate() {
         pyo3::prepare_freethreaded_python();
         let python = r#"
-def validate(request):
+def validate(request, context):
     return True
         "#;
         let constraint_spec = ConstraintSpec::from_python(python.to_string());
@@ -328,9 +613,18 @@ def validate(request):
             operation: Operation::Create,
         };
 
-        let (res, reason, patch) = evaluate_constraint(&constraint, &request);
-        assert!(res, "validate function failed: {}", reason.unwrap());
-        assert!(reason.is_none());
+        let cache = Mutex::new(HashMap::new());
+        let context_reader = crate::context::ContextReader::disabled();
+        let (event_sender, _event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (res, violations, patch) = evaluate_constraint(
+            &cache,
+            &context_reader,
+            &event_sender,
+            &constraint,
+            &request,
+        );
+        assert!(res, "validate function failed: {}", render_violations(&violations));
+        assert!(violations.is_empty());
         assert!(patch.is_none());
     }
 
@@ -338,7 +632,7 @@ def validate(request):
     fn test_simple_evaluate_with_reason() {
         pyo3::prepare_freethreaded_python();
         let python = r#"
-def validate(request):
+def validate(request, context):
     return False, "foobar"
         "#;
         let constraint_spec = ConstraintSpec::from_python(python.to_string());
@@ -355,10 +649,19 @@ def validate(request):
             operation: Operation::Create,
         };
 
-        let (res, reason, patch) = evaluate_constraint(&constraint, &request);
+        let cache = Mutex::new(HashMap::new());
+        let context_reader = crate::context::ContextReader::disabled();
+        let (event_sender, _event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (res, violations, patch) = evaluate_constraint(
+            &cache,
+            &context_reader,
+            &event_sender,
+            &constraint,
+            &request,
+        );
         assert!(!res);
-        assert!(reason.is_some());
-        assert_eq!("foobar".to_string(), reason.unwrap());
+        assert_eq!(1, violations.len());
+        assert_eq!("foobar".to_string(), violations[0].message);
         assert!(patch.is_none());
     }
 
@@ -366,7 +669,7 @@ def validate(request):
     fn test_evaluate_with_invalid_python() {
         pyo3::prepare_freethreaded_python();
         let python = r#"
-def validate(request):
+def validate(request, context):
     return false, "foobar"
         "#;
         let constraint_spec = ConstraintSpec::from_python(python.to_string());
@@ -383,12 +686,21 @@ def validate(request):
             operation: Operation::Create,
         };
 
-        let (res, reason, patch) = evaluate_constraint(&constraint, &request);
+        let cache = Mutex::new(HashMap::new());
+        let context_reader = crate::context::ContextReader::disabled();
+        let (event_sender, _event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (res, violations, patch) = evaluate_constraint(
+            &cache,
+            &context_reader,
+            &event_sender,
+            &constraint,
+            &request,
+        );
         assert!(!res);
-        assert!(reason.is_some());
+        assert_eq!(1, violations.len());
         assert_eq!(
             "Validation function failed: NameError: name 'false' is not defined".to_string(),
-            reason.unwrap()
+            violations[0].message
         );
         assert!(patch.is_none());
     }
@@ -397,7 +709,7 @@ def validate(request):
     fn test_simple_mutate() {
         pyo3::prepare_freethreaded_python();
         let python = r#"
-def validate(request):
+def validate(request, context):
     object = request["object"]
     object["b"] = "2"
     return True, None, object
@@ -417,9 +729,18 @@ def validate(request):
             operation: Operation::Create,
         };
 
-        let (res, reason, patch) = evaluate_constraint(&constraint, &request);
-        assert!(res, "validate function failed: {}", reason.unwrap());
-        assert!(reason.is_none());
+        let cache = Mutex::new(HashMap::new());
+        let context_reader = crate::context::ContextReader::disabled();
+        let (event_sender, _event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (res, violations, patch) = evaluate_constraint(
+            &cache,
+            &context_reader,
+            &event_sender,
+            &constraint,
+            &request,
+        );
+        assert!(res, "validate function failed: {}", render_violations(&violations));
+        assert!(violations.is_empty());
         assert!(patch.is_some());
         let patch = patch.unwrap();
         assert_eq!(1, patch.0.len());
@@ -431,4 +752,48 @@ def validate(request):
             serde_json::to_value(patch.0).unwrap()
         );
     }
+
+    #[test]
+    fn test_evaluate_with_structured_violations() {
+        pyo3::prepare_freethreaded_python();
+        let python = r#"
+def validate(request, context):
+    return [
+        {"message": "too many replicas", "field_path": "spec.replicas", "code": "TOO_MANY_REPLICAS"},
+        "image tag must be pinned",
+    ]
+        "#;
+        let constraint_spec = ConstraintSpec::from_python(python.to_string());
+        let constraint =
+            ConstraintInfo::new("test".to_string(), constraint_spec, Default::default());
+
+        let object = DynamicObject {
+            types: None,
+            metadata: ObjectMeta::default(),
+            data: serde_json::Value::Null,
+        };
+        let request = ValidationRequest {
+            object,
+            operation: Operation::Create,
+        };
+
+        let cache = Mutex::new(HashMap::new());
+        let context_reader = crate::context::ContextReader::disabled();
+        let (event_sender, _event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (res, violations, patch) = evaluate_constraint(
+            &cache,
+            &context_reader,
+            &event_sender,
+            &constraint,
+            &request,
+        );
+        assert!(!res);
+        assert!(patch.is_none());
+        assert_eq!(2, violations.len());
+        assert_eq!("too many replicas", violations[0].message);
+        assert_eq!(Some("spec.replicas".to_string()), violations[0].field_path);
+        assert_eq!(Some("TOO_MANY_REPLICAS".to_string()), violations[0].code);
+        assert_eq!("image tag must be pinned", violations[1].message);
+        assert_eq!(None, violations[1].field_path);
+    }
 }